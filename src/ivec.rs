@@ -1,4 +1,4 @@
-use std::{convert::TryFrom, fmt, hash::{Hash, Hasher}, iter::FromIterator, ops::{Deref, DerefMut}};
+use std::{convert::TryFrom, fmt, hash::{Hash, Hasher}, iter::FromIterator, ops::{Bound, Deref, DerefMut, RangeBounds}};
 use std::mem::ManuallyDrop;
 
 use crate::Arc;
@@ -23,6 +23,7 @@ impl Default for IVec {
 union Data {
     inline: InlineData,
     remote: ManuallyDrop<Arc<[u8]>>,
+    static_ref: &'static [u8],
 }
 
 #[derive(Clone)]
@@ -30,6 +31,7 @@ enum State {
     Inline { len: u8 },
     Remote,
     Subslice { offset: usize, len: usize },
+    Static,
 }
 
 impl Hash for IVec {
@@ -110,11 +112,72 @@ impl IVec {
                         offset: offset + slice_offset,
                         len,
                     },
-                }
+                },
+                State::Static => Self {
+                    data: Data {
+                        static_ref: &self.data.static_ref[slice_offset..slice_offset + len],
+                    },
+                    state: State::Static,
+                },
             }
         }
     }
 
+    /// Create a subslice of this `IVec` using a range, sharing the
+    /// same backing data and reference counter.
+    ///
+    /// `Unbounded` bounds are normalized against `self.len()`, and the
+    /// result is handed off to [`subslice`](IVec::subslice), so the
+    /// inline / remote / subslice backing behavior is preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or if `end > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use sled::IVec;
+    /// let iv = IVec::from(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(&iv.slice(1..3), &[2, 3]);
+    /// assert_eq!(&iv.slice(..2), &[1, 2]);
+    /// assert_eq!(&iv.slice(3..), &[4, 5]);
+    /// assert_eq!(&iv.slice(..), &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end, "range start {start} is greater than range end {end}");
+        assert!(end <= len, "range end {end} is out of bounds for a slice of length {len}");
+
+        self.subslice(start, end - start)
+    }
+
+    /// Create an `IVec` that borrows a `'static` byte slice directly,
+    /// without allocating or copying.
+    ///
+    /// Slices short enough to fit inline (`len <= CUTOFF`) are still
+    /// stored inline, matching the behavior of the other `From`
+    /// implementations.
+    pub fn from_static(s: &'static [u8]) -> Self {
+        if is_inline_candidate(s.len()) {
+            Self::inline(s)
+        } else {
+            Self { data: Data { static_ref: s }, state: State::Static }
+        }
+    }
+
     fn inline(slice: &[u8]) -> Self {
         assert!(is_inline_candidate(slice.len()));
         let mut data = InlineData::default();
@@ -147,10 +210,83 @@ impl IVec {
                     };
                     self.state = State::Remote;
                 }
+                State::Static => {
+                    self.data = Data {
+                        remote: ManuallyDrop::new(Arc::from(self.data.static_ref)),
+                    };
+                    self.state = State::Remote;
+                }
                 _ => {}
             }
         }
     }
+
+    /// Returns a borrowing iterator over the bytes in this `IVec`.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.as_ref().iter().copied()
+    }
+
+    /// Returns an iterator over successive `IVec` views into this
+    /// buffer, each up to `size` bytes long. Each chunk is made with
+    /// [`subslice`](IVec::subslice): chunks of a remote or static
+    /// buffer share its backing data and copy no bytes, while chunks of
+    /// an inline buffer are small inline copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Self> + '_ {
+        assert!(size > 0, "chunk size must be greater than zero");
+
+        let len = self.len();
+        let mut offset = 0;
+
+        std::iter::from_fn(move || {
+            if offset >= len {
+                return None;
+            }
+
+            let chunk_len = std::cmp::min(len - offset, size);
+            let chunk = self.subslice(offset, chunk_len);
+            offset += chunk_len;
+            Some(chunk)
+        })
+    }
+}
+
+/// An owning iterator over the bytes of an `IVec`, produced by
+/// `IVec::into_iter`. Retains the backing storage for its lifetime, so
+/// the yielded bytes stay valid even after the original `IVec` is
+/// dropped.
+pub struct IntoIter {
+    inner: IVec,
+    pos: usize,
+}
+
+impl Iterator for IntoIter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = *self.inner.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.len() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for IVec {
+    type Item = u8;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter { inner: self, pos: 0 }
+    }
 }
 
 impl Clone for IVec {
@@ -161,6 +297,7 @@ impl Clone for IVec {
                 State::Inline { .. } => self.data.clone_as_inline(),
                 State::Remote => self.data.clone_as_remote(),
                 State::Subslice { .. } => self.data.clone_as_remote(),
+                State::Static => self.data.clone_as_static(),
             }
         };
         Self {
@@ -180,6 +317,10 @@ impl Data {
         Self { remote: self.remote.clone() }
     }
 
+    unsafe fn clone_as_static(&self) -> Self {
+        Self { static_ref: self.static_ref }
+    }
+
     unsafe fn strong_count(&self) -> usize {
         Arc::strong_count(&self.remote)
     }
@@ -286,6 +427,7 @@ impl Into<Arc<[u8]>> for IVec {
             State::Inline { .. } => Arc::from(self.as_ref()),
             State::Remote => unsafe { ManuallyDrop::into_inner(self.data.remote.clone()) },
             State::Subslice { .. } => self.deref().into(),
+            State::Static => unsafe { Arc::from(self.data.static_ref) },
         }
     }
 }
@@ -312,6 +454,7 @@ impl AsRef<[u8]> for IVec {
                 State::Subslice { offset, len } => {
                     &self.data.remote[offset..offset + len]
                 }
+                State::Static => self.data.static_ref,
             }
         }
     }
@@ -339,6 +482,7 @@ impl AsMut<[u8]> for IVec {
                 State::Subslice { offset, len } => {
                     &mut Arc::get_mut(&mut self.data.remote).unwrap()[offset..offset + len]
                 }
+                State::Static => unreachable!("make_mut upgrades Static before as_mut runs"),
             }
         }
     }
@@ -376,6 +520,61 @@ impl fmt::Debug for IVec {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for IVec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bytes(self.as_ref())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IVec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IVecVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IVecVisitor {
+            type Value = IVec;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a byte sequence")
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(IVec::from(v))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(IVec::from(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(IVec::from(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(IVecVisitor)
+    }
+}
+
 impl Drop for IVec {
     #[allow(unsafe_code)]
     fn drop(&mut self) {
@@ -388,6 +587,104 @@ impl Drop for IVec {
     }
 }
 
+impl std::io::Read for IVec {
+    /// Reads from the front of this `IVec`, consuming the bytes that
+    /// were read. Repeated calls act like a cursor advancing over
+    /// `as_ref()`.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = std::cmp::min(buf.len(), self.len());
+        buf[..len].copy_from_slice(&self[..len]);
+        *self = self.subslice(len, self.len() - len);
+        Ok(len)
+    }
+}
+
+/// An append-only, reference-counted byte buffer that readers can take
+/// cheap, zero-copy `IVec` snapshots of while it continues to grow.
+///
+/// Bytes already handed out in a snapshot are never mutated in place:
+/// growing the buffer either writes into already-allocated spare
+/// capacity (when no snapshot is outstanding and the capacity
+/// suffices) or allocates a fresh backing segment and copies the
+/// previously-committed bytes into it (when a snapshot is sharing the
+/// current allocation or the spare capacity is exhausted), so any `IVec`
+/// returned by [`as_ivec`](IVecBuf::as_ivec) or
+/// [`snapshot`](IVecBuf::snapshot) stays valid no matter how much more
+/// is written afterward.
+#[derive(Default)]
+pub struct IVecBuf {
+    buf: Arc<[u8]>,
+    len: usize,
+}
+
+impl IVecBuf {
+    /// Creates an empty `IVecBuf`.
+    pub fn new() -> Self {
+        Self { buf: Arc::from(&[][..]), len: 0 }
+    }
+
+    /// Appends a single byte.
+    pub fn push(&mut self, byte: u8) {
+        self.extend_from_slice(&[byte]);
+    }
+
+    /// Appends a slice of bytes, growing the backing allocation (and
+    /// copying the bytes committed so far into a fresh one) only when
+    /// spare capacity is exhausted or is shared with an outstanding
+    /// snapshot.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+
+        let needed = self.len + bytes.len();
+        if needed > self.buf.len() || Arc::strong_count(&self.buf) != 1 {
+            let new_cap = std::cmp::max(needed, self.buf.len() * 2);
+            let mut grown = vec![0_u8; new_cap];
+            grown[..self.len].copy_from_slice(&self.buf[..self.len]);
+            self.buf = Arc::from(grown);
+        }
+
+        Arc::get_mut(&mut self.buf).unwrap()[self.len..needed].copy_from_slice(bytes);
+        self.len = needed;
+    }
+
+    /// Returns a cheap `IVec` view over the bytes committed so far,
+    /// sharing the same backing allocation rather than copying it.
+    pub fn as_ivec(&self) -> IVec {
+        let whole = IVec::remote(Arc::clone(&self.buf));
+        if self.len == self.buf.len() {
+            whole
+        } else {
+            whole.subslice(0, self.len)
+        }
+    }
+
+    /// Alias for [`as_ivec`](IVecBuf::as_ivec).
+    pub fn snapshot(&self) -> IVec {
+        self.as_ivec()
+    }
+}
+
+impl std::io::Write for IVecBuf {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl From<IVecBuf> for IVec {
+    /// Hands the finished buffer over to an `IVec`, reusing its
+    /// backing allocation as a remote `Arc<[u8]>` rather than copying.
+    fn from(buf: IVecBuf) -> Self {
+        buf.as_ivec()
+    }
+}
+
 #[test]
 fn ivec_usage() {
     let iv1 = IVec::from(vec![1, 2, 3]);
@@ -420,6 +717,24 @@ fn subslice_usage_01() {
     let _subslice = iv1.subslice(3, 1);
 }
 
+#[test]
+#[should_panic]
+fn slice_usage_00() {
+    let iv1 = IVec::from(vec![1, 2, 3, 4, 5]);
+    // built from variables rather than a literal `3..1` so this
+    // intentionally-backwards range doesn't trip clippy's
+    // reversed_empty_ranges lint
+    let (start, end) = (3, 1);
+    let _slice = iv1.slice(start..end);
+}
+
+#[test]
+#[should_panic]
+fn slice_usage_01() {
+    let iv1 = IVec::from(vec![1, 2, 3, 4, 5]);
+    let _slice = iv1.slice(..10);
+}
+
 #[test]
 fn ivec_as_mut_identity() {
     let initial = &[1];
@@ -440,10 +755,142 @@ fn ivec_alignment() {
         State::Inline { .. } => "inline",
         State::Remote => "remote",
         State::Subslice { .. } => "subslice",
+        State::Static => "static",
     };
     assert_eq!(iv1.as_ptr() as usize % 8, 0, "{kind}");
 }
 
+#[test]
+fn ivec_from_static() {
+    static BYTES: [u8; 32] = [7; 32];
+
+    let iv = IVec::from_static(&BYTES);
+    assert_eq!(iv, &BYTES[..]);
+    assert!(matches!(iv.state, State::Static));
+
+    let cloned = iv.clone();
+    assert_eq!(cloned, &BYTES[..]);
+
+    let sub = iv.subslice(4, 8);
+    assert_eq!(&sub, &BYTES[4..12]);
+    assert!(matches!(sub.state, State::Static));
+
+    let mut to_mutate = iv.clone();
+    to_mutate.as_mut()[0] = 0;
+    assert_eq!(to_mutate[0], 0);
+    assert_eq!(BYTES[0], 7, "mutating should not affect the static slice");
+
+    let small = IVec::from_static(&[1, 2, 3]);
+    assert!(matches!(small.state, State::Inline { .. }));
+}
+
+#[test]
+fn ivec_into_iter() {
+    let iv = IVec::from(vec![1, 2, 3]);
+    let collected: Vec<u8> = iv.clone().into_iter().collect();
+    assert_eq!(collected, vec![1, 2, 3]);
+
+    // the owning iterator keeps the backing bytes alive even after
+    // the original `IVec` is dropped.
+    let mut into_iter = iv.into_iter();
+    assert_eq!(into_iter.next(), Some(1));
+    assert_eq!(into_iter.next(), Some(2));
+    assert_eq!(into_iter.next(), Some(3));
+    assert_eq!(into_iter.next(), None);
+}
+
+#[test]
+fn ivec_iter() {
+    let iv = IVec::from(vec![1, 2, 3]);
+    let collected: Vec<u8> = iv.iter().collect();
+    assert_eq!(collected, vec![1, 2, 3]);
+}
+
+#[test]
+fn ivec_chunks() {
+    let iv = IVec::from(vec![1, 2, 3, 4, 5]);
+    let chunks: Vec<IVec> = iv.chunks(2).collect();
+    assert_eq!(chunks, vec![
+        IVec::from(vec![1, 2]),
+        IVec::from(vec![3, 4]),
+        IVec::from(vec![5]),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn ivec_chunks_zero_size() {
+    let iv = IVec::from(vec![1, 2, 3]);
+    let _ = iv.chunks(0).next();
+}
+
+#[test]
+fn ivec_read() {
+    use std::io::Read;
+
+    let mut iv = IVec::from(vec![1, 2, 3, 4, 5]);
+    let mut buf = [0; 3];
+    assert_eq!(iv.read(&mut buf).unwrap(), 3);
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(&iv, &[4, 5]);
+
+    let mut rest = Vec::new();
+    iv.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, vec![4, 5]);
+    assert_eq!(&iv, &[]);
+}
+
+#[test]
+fn ivec_buf_basic() {
+    use std::io::Write;
+
+    let mut buf = IVecBuf::new();
+    buf.push(1);
+    buf.extend_from_slice(&[2, 3]);
+    write!(buf, "").unwrap();
+    buf.write_all(&[4, 5]).unwrap();
+
+    let snapshot = buf.snapshot();
+    assert_eq!(&snapshot, &[1, 2, 3, 4, 5]);
+
+    // writing more doesn't disturb a previously taken snapshot
+    buf.push(6);
+    assert_eq!(&snapshot, &[1, 2, 3, 4, 5]);
+    assert_eq!(&buf.as_ivec(), &[1, 2, 3, 4, 5, 6]);
+
+    let iv: IVec = buf.into();
+    assert_eq!(&iv, &[1, 2, 3, 4, 5, 6]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ivec_serde_roundtrip() {
+    // serde_json has no native byte-string representation, so it
+    // serializes `serialize_bytes` output as a JSON array and drives
+    // our `Deserialize` impl through `visit_seq` on the way back.
+    let small = IVec::from(vec![1, 2, 3]);
+    assert!(matches!(small.state, State::Inline { .. }));
+    let encoded = serde_json::to_vec(&small).unwrap();
+    let decoded: IVec = serde_json::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, small);
+    assert!(matches!(decoded.state, State::Inline { .. }));
+
+    let large = IVec::from(vec![7; 128]);
+    assert!(matches!(large.state, State::Remote));
+    let encoded = serde_json::to_vec(&large).unwrap();
+    let decoded: IVec = serde_json::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, large);
+    assert!(matches!(decoded.state, State::Remote));
+}
+
+#[cfg(test)]
+impl quickcheck::Arbitrary for IVec {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let bytes: Vec<u8> = quickcheck::Arbitrary::arbitrary(g);
+        IVec::from(bytes)
+    }
+}
+
 #[cfg(test)]
 mod qc {
     use super::IVec;
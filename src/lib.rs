@@ -0,0 +1,5 @@
+mod ivec;
+
+pub use ivec::{IVec, IVecBuf};
+
+pub(crate) use std::sync::Arc;